@@ -0,0 +1,200 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A single Unicode code point in the range `0..=0x10FFFF`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Codepoint(pub u32);
+
+/// An inclusive range of code points, as found in the first field of most UCD lines.
+///
+/// A line naming a single code point (`0041`) parses to a range where
+/// `start == end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CodepointRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// An error returned when a code point or code point range fails to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCodepointError(String);
+
+impl ParseCodepointError {
+    pub(crate) fn empty() -> Self {
+        ParseCodepointError(String::new())
+    }
+}
+
+impl fmt::Display for ParseCodepointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid codepoint '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseCodepointError {}
+
+impl FromStr for Codepoint {
+    type Err = ParseCodepointError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let value =
+            u32::from_str_radix(s, 16).map_err(|_| ParseCodepointError(s.to_string()))?;
+        if value > 0x10FFFF {
+            return Err(ParseCodepointError(s.to_string()));
+        }
+        Ok(Codepoint(value))
+    }
+}
+
+impl fmt::Display for Codepoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04X}", self.0)
+    }
+}
+
+impl FromStr for CodepointRange {
+    type Err = ParseCodepointError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((start, end)) = s.split_once("..") {
+            let start = start.parse::<Codepoint>()?.0;
+            let end = end.parse::<Codepoint>()?.0;
+            return Ok(CodepointRange { start, end });
+        }
+        // A space-separated sequence (`0061 0300`) is not a contiguous range;
+        // reject it here instead of silently collapsing it to one.
+        if s.split_whitespace().count() > 1 {
+            return Err(ParseCodepointError(s.to_string()));
+        }
+        let value = s.parse::<Codepoint>()?.0;
+        Ok(CodepointRange {
+            start: value,
+            end: value,
+        })
+    }
+}
+
+impl fmt::Display for CodepointRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{:04X}", self.start)
+        } else {
+            write!(f, "{:04X}..{:04X}", self.start, self.end)
+        }
+    }
+}
+
+/// A sequence of one or more code points, as found in fields such as
+/// case-folding or decomposition mappings (`0061 0300`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CodepointSequence(pub Vec<u32>);
+
+impl FromStr for CodepointSequence {
+    type Err = ParseCodepointError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let values = s
+            .split_whitespace()
+            .map(|value| value.parse::<Codepoint>().map(|c| c.0))
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.is_empty() {
+            return Err(ParseCodepointError(s.to_string()));
+        }
+        Ok(CodepointSequence(values))
+    }
+}
+
+impl fmt::Display for CodepointSequence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut values = self.0.iter();
+        if let Some(value) = values.next() {
+            write!(f, "{:04X}", value)?;
+        }
+        for value in values {
+            write!(f, " {:04X}", value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codepoint_parses_hex() {
+        assert_eq!("0041".parse(), Ok(Codepoint(0x41)));
+        assert_eq!("10FFFF".parse(), Ok(Codepoint(0x10FFFF)));
+    }
+
+    #[test]
+    fn codepoint_rejects_out_of_range() {
+        assert!("110000".parse::<Codepoint>().is_err());
+    }
+
+    #[test]
+    fn codepoint_rejects_empty() {
+        assert!("".parse::<Codepoint>().is_err());
+    }
+
+    #[test]
+    fn codepoint_range_parses_single_value() {
+        assert_eq!(
+            "0041".parse(),
+            Ok(CodepointRange {
+                start: 0x41,
+                end: 0x41
+            })
+        );
+    }
+
+    #[test]
+    fn codepoint_range_parses_dotted_range() {
+        assert_eq!(
+            "0000..007F".parse(),
+            Ok(CodepointRange {
+                start: 0,
+                end: 0x7F
+            })
+        );
+    }
+
+    #[test]
+    fn codepoint_range_rejects_sequence() {
+        assert!("0061 0300".parse::<CodepointRange>().is_err());
+    }
+
+    #[test]
+    fn codepoint_range_round_trips_through_display() {
+        let range = CodepointRange {
+            start: 0,
+            end: 0x7F,
+        };
+        assert_eq!(range.to_string().parse(), Ok(range));
+        let single = CodepointRange {
+            start: 0x41,
+            end: 0x41,
+        };
+        assert_eq!(single.to_string(), "0041");
+    }
+
+    #[test]
+    fn codepoint_sequence_parses_multiple_values() {
+        assert_eq!(
+            "0061 0300".parse(),
+            Ok(CodepointSequence(vec![0x61, 0x300]))
+        );
+    }
+
+    #[test]
+    fn codepoint_sequence_parses_single_value() {
+        assert_eq!("0061".parse(), Ok(CodepointSequence(vec![0x61])));
+    }
+
+    #[test]
+    fn codepoint_sequence_round_trips_through_display() {
+        let sequence = CodepointSequence(vec![0x61, 0x300]);
+        assert_eq!(sequence.to_string(), "0061 0300");
+        assert_eq!(sequence.to_string().parse(), Ok(sequence));
+    }
+}
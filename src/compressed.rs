@@ -0,0 +1,113 @@
+use flate2::read::MultiGzDecoder;
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::UCD;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Either a plain file or one transparently gzip-decompressed, so callers of
+/// [`UCD::open_compressed`] can treat both the same way.
+pub enum MaybeCompressed<T> {
+    Plain(T),
+    Gzip(MultiGzDecoder<T>),
+}
+
+impl<T: Read> Read for MaybeCompressed<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            MaybeCompressed::Plain(r) => r.read(buf),
+            MaybeCompressed::Gzip(r) => r.read(buf),
+        }
+    }
+}
+
+impl UCD<BufReader<MaybeCompressed<File>>> {
+    /// Like [`UCD::open`], but transparently decompresses gzip input.
+    ///
+    /// Whether a file is gzip-compressed is detected from its magic bytes
+    /// first, falling back to a `.gz` extension; a gzip stream made of
+    /// multiple concatenated members (as some archive tools produce) is
+    /// decoded as a single logical stream.
+    pub fn open_compressed<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let mut file = File::open(path)?;
+        let mut magic = [0u8; 2];
+        let read = file.read(&mut magic)?;
+        file.seek(SeekFrom::Start(0))?;
+        let is_gzip =
+            (read == 2 && magic == GZIP_MAGIC) || path.extension() == Some(OsStr::new("gz"));
+        let reader = if is_gzip {
+            MaybeCompressed::Gzip(MultiGzDecoder::new(file))
+        } else {
+            MaybeCompressed::Plain(file)
+        };
+        Ok(Self::with_path(BufReader::new(reader), path.to_path_buf()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn gzip(contents: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(contents).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn read_to_string(path: &Path) -> String {
+        let mut s = String::new();
+        UCD::open_compressed(path)
+            .unwrap()
+            .reader
+            .read_to_string(&mut s)
+            .unwrap();
+        s
+    }
+
+    #[test]
+    fn detects_gzip_by_magic_bytes_without_gz_extension() {
+        let path = std::env::temp_dir().join("ucd-parse-test-magic.txt");
+        std::fs::write(&path, gzip(b"0041 ; Latin")).unwrap();
+        assert_eq!(read_to_string(&path), "0041 ; Latin");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn gz_extension_is_treated_as_gzip_even_when_too_short_for_the_magic_bytes() {
+        // A one-byte file can't satisfy the magic-byte check (which needs two
+        // bytes), so this only decodes as gzip because of the `.gz` extension.
+        let path = std::env::temp_dir().join("ucd-parse-test-fallback.gz");
+        std::fs::write(&path, b"x").unwrap();
+        UCD::open_compressed(&path)
+            .unwrap()
+            .reader
+            .read_to_string(&mut String::new())
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn plain_file_is_read_unchanged() {
+        let path = std::env::temp_dir().join("ucd-parse-test-plain.txt");
+        std::fs::write(&path, b"0041 ; Latin").unwrap();
+        assert_eq!(read_to_string(&path), "0041 ; Latin");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn decodes_multiple_concatenated_gzip_members_as_one_stream() {
+        let path = std::env::temp_dir().join("ucd-parse-test-multimember.gz");
+        let mut contents = gzip(b"0041 ; Latin\n");
+        contents.extend(gzip(b"0391 ; Greek\n"));
+        std::fs::write(&path, contents).unwrap();
+        assert_eq!(read_to_string(&path), "0041 ; Latin\n0391 ; Greek\n");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    Age, CoreProperty, EmojiProperty, GraphemeClusterBreak, LineBreak, Records, UcdFile, UCD,
+};
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+/// A handle onto an extracted `public/UCD/` directory tree.
+///
+/// Each accessor locates the conventional file for one property relative to
+/// the root, so callers don't need to know whether it lives at the root or
+/// under a subdirectory such as `extracted/`, `emoji/` or `auxiliary/`.
+pub struct Ucd {
+    root: PathBuf,
+}
+
+impl Ucd {
+    pub fn from_dir<P: AsRef<Path>>(root: P) -> Self {
+        Ucd {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    fn records_in<R: UcdFile>(
+        &self,
+        subdir: Option<&str>,
+    ) -> io::Result<Records<io::Lines<BufReader<File>>, R>> {
+        let path = match subdir {
+            Some(subdir) => self.root.join(subdir).join(R::file_name()),
+            None => self.root.join(R::file_name()),
+        };
+        Ok(UCD::open(path)?.records())
+    }
+
+    pub fn ages(&self) -> io::Result<Records<io::Lines<BufReader<File>>, Age>> {
+        self.records_in(None)
+    }
+
+    pub fn line_breaks(&self) -> io::Result<Records<io::Lines<BufReader<File>>, LineBreak>> {
+        self.records_in(None)
+    }
+
+    pub fn core_properties(&self) -> io::Result<Records<io::Lines<BufReader<File>>, CoreProperty>> {
+        self.records_in(None)
+    }
+
+    pub fn emoji_properties(&self) -> io::Result<Records<io::Lines<BufReader<File>>, EmojiProperty>> {
+        self.records_in(Some("emoji"))
+    }
+
+    pub fn grapheme_cluster_breaks(
+        &self,
+    ) -> io::Result<Records<io::Lines<BufReader<File>>, GraphemeClusterBreak>> {
+        self.records_in(Some("auxiliary"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Builds a fixture tree with one file per accessor, at the subdirectory
+    /// each accessor is expected to look under, and returns the `Ucd` rooted
+    /// on it.
+    fn fixture(name: &str) -> Ucd {
+        let root = std::env::temp_dir().join(format!("ucd-parse-test-dir-{}", name));
+        fs::create_dir_all(root.join("emoji")).unwrap();
+        fs::create_dir_all(root.join("auxiliary")).unwrap();
+        fs::write(root.join("DerivedAge.txt"), "0041 ; 1.1").unwrap();
+        fs::write(root.join("LineBreak.txt"), "0041 ; AL").unwrap();
+        fs::write(root.join("DerivedCoreProperties.txt"), "0041 ; Alphabetic").unwrap();
+        fs::write(root.join("emoji").join("emoji-data.txt"), "0041 ; Emoji").unwrap();
+        fs::write(
+            root.join("auxiliary").join("GraphemeBreakProperty.txt"),
+            "0041 ; Control",
+        )
+        .unwrap();
+        Ucd::from_dir(root)
+    }
+
+    #[test]
+    fn ages_and_line_breaks_and_core_properties_resolve_at_root() {
+        let ucd = fixture("root");
+        assert_eq!(ucd.ages().unwrap().next().unwrap().unwrap().version, "1.1");
+        assert_eq!(
+            ucd.line_breaks().unwrap().next().unwrap().unwrap().class,
+            "AL"
+        );
+        assert_eq!(
+            ucd.core_properties()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap()
+                .property,
+            "Alphabetic"
+        );
+        fs::remove_dir_all(&ucd.root).unwrap();
+    }
+
+    #[test]
+    fn emoji_properties_resolve_under_emoji_subdirectory() {
+        let ucd = fixture("emoji");
+        assert_eq!(
+            ucd.emoji_properties()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap()
+                .property,
+            "Emoji"
+        );
+        fs::remove_dir_all(&ucd.root).unwrap();
+    }
+
+    #[test]
+    fn grapheme_cluster_breaks_resolve_under_auxiliary_subdirectory() {
+        let ucd = fixture("auxiliary");
+        assert_eq!(
+            ucd.grapheme_cluster_breaks()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap()
+                .class,
+            "Control"
+        );
+        fs::remove_dir_all(&ucd.root).unwrap();
+    }
+}
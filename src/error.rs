@@ -0,0 +1,93 @@
+use crate::record::FieldError;
+use crate::ParseCodepointError;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// A structured error produced while parsing a UCD data file, carrying the
+/// 1-based physical line number (and, when known, the file path) of the line
+/// that failed.
+#[derive(Debug)]
+pub struct Error {
+    pub line: usize,
+    pub path: Option<PathBuf>,
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    pub(crate) fn new(line: usize, path: Option<PathBuf>, kind: ErrorKind) -> Self {
+        Error { line, path, kind }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}:{}: {}", path.display(), self.line, self.kind),
+            None => write!(f, "{}: {}", self.line, self.kind),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The kind of failure recorded by an [`Error`].
+#[derive(Debug)]
+pub enum ErrorKind {
+    Io(io::Error),
+    InvalidCodepoint(ParseCodepointError),
+    MissingField,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Io(e) => write!(f, "{}", e),
+            ErrorKind::InvalidCodepoint(e) => write!(f, "{}", e),
+            ErrorKind::MissingField => write!(f, "missing field"),
+        }
+    }
+}
+
+impl From<FieldError> for ErrorKind {
+    fn from(e: FieldError) -> Self {
+        match e {
+            FieldError::Codepoint(e) => ErrorKind::InvalidCodepoint(e),
+            FieldError::MissingField => ErrorKind::MissingField,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Age, UCD};
+
+    #[test]
+    fn reports_the_physical_line_and_path_of_a_parse_failure() {
+        let path = std::env::temp_dir().join("ucd-parse-test-error-line.txt");
+        std::fs::write(
+            &path,
+            "# DerivedAge.txt\n\
+             # Comment line\n\
+             \n\
+             0041 ; 1.1\n\
+             XYZ ; 1.1\n",
+        )
+        .unwrap();
+
+        let mut records = UCD::open(&path).unwrap().records::<Age>();
+        let first = records.next().unwrap().unwrap();
+        assert_eq!(first.version, "1.1");
+        let error = match records.next().unwrap() {
+            Ok(_) => panic!("expected a parse error"),
+            Err(e) => e,
+        };
+        assert_eq!(error.line, 5);
+        assert_eq!(
+            error.to_string(),
+            format!("{}:5: invalid codepoint 'XYZ'", path.display())
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
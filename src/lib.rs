@@ -1,9 +1,22 @@
-#![feature(with_options)]
+mod codepoint;
+mod compressed;
+mod dir;
+mod error;
+mod record;
+
+pub use codepoint::{Codepoint, CodepointRange, CodepointSequence, ParseCodepointError};
+pub use compressed::MaybeCompressed;
+pub use dir::Ucd;
+pub use error::{Error, ErrorKind};
+pub use record::{
+    Age, CoreProperty, EmojiProperty, FieldError, GraphemeClusterBreak, LineBreak, Records,
+    UcdFile,
+};
 
 use std::{
     fs::{self, File},
     io::{self, BufRead, BufReader},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 /**
@@ -38,32 +51,78 @@ use std::{
  * # }
  * ```
  */
-pub struct UCD<T>(T);
+pub struct UCD<T> {
+    reader: T,
+    path: Option<PathBuf>,
+}
 
 impl<T> UCD<T> {
     pub fn new(src: T) -> Self {
-        Self(src)
+        Self {
+            reader: src,
+            path: None,
+        }
+    }
+    pub(crate) fn with_path(src: T, path: PathBuf) -> Self {
+        Self {
+            reader: src,
+            path: Some(path),
+        }
     }
 }
 
 impl UCD<BufReader<File>> {
     pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
-        Ok(Self::new(BufReader::new(File::open(path)?)))
+        let path = path.as_ref();
+        Ok(Self::with_path(
+            BufReader::new(File::open(path)?),
+            path.to_path_buf(),
+        ))
     }
     pub fn with_options<P: AsRef<Path>>() -> OpenOptions {
-        OpenOptions(File::with_options())
+        OpenOptions(File::options())
     }
 }
 
 impl<T: io::Read> UCD<BufReader<T>> {
     pub fn ucd_lines(self) -> UCDLines<io::Lines<BufReader<T>>> {
-        UCDLines(self.0.lines())
+        UCDLines::new(self.reader.lines(), false)
+    }
+    /// Like [`ucd_lines`](Self::ucd_lines), but also yields `# @missing:` comments
+    /// as [`UCDLine::Missing`] instead of discarding them.
+    pub fn ucd_lines_with_missing(self) -> UCDLines<io::Lines<BufReader<T>>> {
+        UCDLines::new(self.reader.lines(), true)
+    }
+    /// Parses every line as a typed record `R`, reporting failures as
+    /// [`Error`] with the physical line number of the offending line.
+    ///
+    /// ```no_run
+    /// # use ucd_parse::{Age, UCD};
+    /// # fn main() -> std::io::Result<()> {
+    /// for age in UCD::open("DerivedAge.txt")?.records::<Age>() {
+    ///     let age = age.unwrap();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records<R: UcdFile>(self) -> Records<io::Lines<BufReader<T>>, R> {
+        let path = self.path.clone();
+        Records {
+            inner: self.ucd_lines(),
+            path,
+            marker: std::marker::PhantomData,
+        }
     }
 }
 
 impl<'a> UCD<&'a str> {
     pub fn ucd_lines(self) -> UCDLines<std::str::Lines<'a>> {
-        UCDLines(self.0.lines())
+        UCDLines::new(self.reader.lines(), false)
+    }
+    /// Like [`ucd_lines`](Self::ucd_lines), but also yields `# @missing:` comments
+    /// as [`UCDLine::Missing`] instead of discarding them.
+    pub fn ucd_lines_with_missing(self) -> UCDLines<std::str::Lines<'a>> {
+        UCDLines::new(self.reader.lines(), true)
     }
 }
 
@@ -98,26 +157,69 @@ impl OpenOptions {
         self
     }
     pub fn open<P: AsRef<Path>>(&self, path: P) -> io::Result<UCD<BufReader<File>>> {
-        Ok(UCD::new(BufReader::new(self.0.open(path)?)))
+        let path = path.as_ref();
+        Ok(UCD::with_path(
+            BufReader::new(self.0.open(path)?),
+            path.to_path_buf(),
+        ))
     }
 }
 
 /// iterator of the row containing the column in the UCD text (ignore blank lines)
-pub struct UCDLines<T>(T);
+///
+/// Tracks the 1-based physical line number of the underlying source as it
+/// skips comments and blanks, so consumers (such as [`Records`]) can report
+/// exactly which line a later parse failure came from.
+pub struct UCDLines<T> {
+    inner: T,
+    with_missing: bool,
+    line: usize,
+}
+
+impl<T> UCDLines<T> {
+    fn new(inner: T, with_missing: bool) -> Self {
+        UCDLines {
+            inner,
+            with_missing,
+            line: 0,
+        }
+    }
+    /// The physical line number of the line most recently returned.
+    pub(crate) fn line(&self) -> usize {
+        self.line
+    }
+}
+
+/// If `line` is a `# @missing: ...` comment, returns the part after the marker.
+fn missing_field(line: &str) -> Option<&str> {
+    line.trim_start()
+        .strip_prefix('#')?
+        .trim_start()
+        .strip_prefix("@missing:")
+}
 
 impl<T: BufRead> Iterator for UCDLines<io::Lines<T>> {
     type Item = io::Result<UCDLine<String>>;
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            match self.0.next()? {
+            match self.inner.next()? {
                 Ok(line) => {
-                    if let Some('#') | None = line.trim_start().chars().next() {
+                    self.line += 1;
+                    if let Some(rest) = missing_field(&line) {
+                        if self.with_missing {
+                            return Some(Ok(UCDLine::Missing(rest.trim().to_string())));
+                        }
+                        continue;
+                    } else if let Some('#') | None = line.trim_start().chars().next() {
                         continue;
                     } else {
-                        return Some(Ok(UCDLine(line)));
+                        return Some(Ok(UCDLine::Record(line)));
                     }
                 }
-                Err(e) => return Some(Err(e)),
+                Err(e) => {
+                    self.line += 1;
+                    return Some(Err(e));
+                }
             }
         }
     }
@@ -127,25 +229,54 @@ impl<'a> Iterator for UCDLines<std::str::Lines<'a>> {
     type Item = UCDLine<&'a str>;
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let line = self.0.next()?;
-            if let Some('#') | None = line.trim_start().chars().next() {
+            let line = self.inner.next()?;
+            self.line += 1;
+            if let Some(rest) = missing_field(line) {
+                if self.with_missing {
+                    return Some(UCDLine::Missing(rest.trim()));
+                }
+                continue;
+            } else if let Some('#') | None = line.trim_start().chars().next() {
                 continue;
             } else {
-                return Some(UCDLine(line));
+                return Some(UCDLine::Record(line));
             }
         }
     }
 }
 
-/// A non-empty line
-pub struct UCDLine<T>(T);
+/// A non-empty line.
+pub enum UCDLine<T> {
+    /// An ordinary semicolon-delimited record line.
+    Record(T),
+    /// A `# @missing: ...` comment giving the default value for code points
+    /// not otherwise listed, with the `@missing:` marker already stripped.
+    Missing(T),
+}
+
+impl<T> UCDLine<T> {
+    fn content(&self) -> &T {
+        match self {
+            UCDLine::Record(s) | UCDLine::Missing(s) => s,
+        }
+    }
+}
+
+impl<T: AsRef<str>> UCDLine<T> {
+    /// The line's text (trailing comment included): the whole line for a
+    /// [`Record`](UCDLine::Record), and the trimmed text after the `@missing:`
+    /// marker for a [`Missing`](UCDLine::Missing) default.
+    pub fn as_str(&self) -> &str {
+        self.content().as_ref()
+    }
+}
 
 impl<'a> IntoIterator for &'a UCDLine<String> {
     type Item = &'a str;
     type IntoIter = UCDLineIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        UCDLineIter(self.0.split('#').next().unwrap().split(';'))
+        UCDLineIter(self.content().split('#').next().unwrap().split(';'))
     }
 }
 
@@ -154,7 +285,31 @@ impl<'a> IntoIterator for UCDLine<&'a str> {
     type IntoIter = UCDLineIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        UCDLineIter(self.0.split('#').next().unwrap().split(';'))
+        let content = match self {
+            UCDLine::Record(s) | UCDLine::Missing(s) => s,
+        };
+        UCDLineIter(content.split('#').next().unwrap().split(';'))
+    }
+}
+
+impl UCDLine<String> {
+    /// Parses the first field of the line as a [`CodepointRange`].
+    pub fn codepoints(&self) -> Result<CodepointRange, ParseCodepointError> {
+        self.into_iter()
+            .next()
+            .ok_or_else(ParseCodepointError::empty)?
+            .parse()
+    }
+}
+
+impl UCDLine<&str> {
+    /// Parses the first field of the line as a [`CodepointRange`].
+    pub fn codepoints(&self) -> Result<CodepointRange, ParseCodepointError> {
+        UCDLine::Record(*self.content())
+            .into_iter()
+            .next()
+            .ok_or_else(ParseCodepointError::empty)?
+            .parse()
     }
 }
 
@@ -171,3 +326,37 @@ impl<'a> Iterator for UCDLineIter<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRC: &str = "\
+# Comment
+# @missing: 0000..10FFFF; X
+0041 ; Latin
+";
+
+    #[test]
+    fn ucd_lines_drops_missing_comments_and_ordinary_comments() {
+        let lines: Vec<&str> = UCD::new(SRC)
+            .ucd_lines()
+            .map(|line| *line.content())
+            .collect();
+        assert_eq!(lines, vec!["0041 ; Latin"]);
+    }
+
+    #[test]
+    fn ucd_lines_with_missing_surfaces_the_missing_comment_but_not_ordinary_comments() {
+        let lines: Vec<UCDLine<&str>> = UCD::new(SRC).ucd_lines_with_missing().collect();
+        assert_eq!(lines.len(), 2);
+        match &lines[0] {
+            UCDLine::Missing(s) => assert_eq!(*s, "0000..10FFFF; X"),
+            UCDLine::Record(_) => panic!("expected a Missing line"),
+        }
+        match &lines[1] {
+            UCDLine::Record(s) => assert_eq!(*s, "0041 ; Latin"),
+            UCDLine::Missing(_) => panic!("expected a Record line"),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{CodepointRange, Error, ErrorKind, ParseCodepointError, UCDLine, UCDLines};
+use std::fmt;
+use std::io::{self, BufRead};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// A UCD record type that can be read, one row per physical line, out of a
+/// single UCD data file.
+///
+/// Implementors parse their fields from the decoded [`UCDLine`] content via
+/// `FromStr`, typically starting with a [`CodepointRange`] taken from the
+/// first field.
+pub trait UcdFile: Sized + FromStr {
+    /// The conventional file name (without any subdirectory such as `emoji/`
+    /// or `auxiliary/`) that this record type is read from.
+    fn file_name() -> &'static str;
+}
+
+/// The error produced while parsing the fields of a single [`UcdFile`] record.
+#[derive(Debug)]
+pub enum FieldError {
+    Codepoint(ParseCodepointError),
+    MissingField,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FieldError::Codepoint(e) => write!(f, "{}", e),
+            FieldError::MissingField => write!(f, "missing field"),
+        }
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+impl From<ParseCodepointError> for FieldError {
+    fn from(e: ParseCodepointError) -> Self {
+        FieldError::Codepoint(e)
+    }
+}
+
+/// An iterator adapting [`UCDLines`] into typed records of kind `R`, reporting
+/// failures as [`Error`] with the physical line number (and, when known, the
+/// file path) of the offending line.
+pub struct Records<T, R> {
+    pub(crate) inner: UCDLines<T>,
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) marker: PhantomData<R>,
+}
+
+impl<U: BufRead, R: UcdFile> Iterator for Records<io::Lines<U>, R>
+where
+    R::Err: Into<ErrorKind>,
+{
+    type Item = Result<R, Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next()? {
+            Ok(line) => Some(line.as_str().parse::<R>().map_err(|e| {
+                Error::new(self.inner.line(), self.path.clone(), e.into())
+            })),
+            Err(e) => Some(Err(Error::new(
+                self.inner.line(),
+                self.path.clone(),
+                ErrorKind::Io(e),
+            ))),
+        }
+    }
+}
+
+/// Defines a record type with a [`CodepointRange`] followed by a single
+/// string field, along with its `FromStr` and [`UcdFile`] impls.
+///
+/// This covers the common `range ; value` layout shared by all of the
+/// record types below.
+macro_rules! simple_ucd_record {
+    ($(#[$doc:meta])* $name:ident, $field:ident, $file_name:expr) => {
+        $(#[$doc])*
+        pub struct $name {
+            pub codepoints: CodepointRange,
+            pub $field: String,
+        }
+
+        impl FromStr for $name {
+            type Err = FieldError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut fields = UCDLine::Record(s).into_iter();
+                let codepoints = fields.next().ok_or(FieldError::MissingField)?.parse()?;
+                let $field = fields.next().ok_or(FieldError::MissingField)?.to_string();
+                Ok($name { codepoints, $field })
+            }
+        }
+
+        impl UcdFile for $name {
+            fn file_name() -> &'static str {
+                $file_name
+            }
+        }
+    };
+}
+
+simple_ucd_record!(
+    /// A code point or code point range's assigned Unicode version, as found
+    /// in `DerivedAge.txt`.
+    Age,
+    version,
+    "DerivedAge.txt"
+);
+
+simple_ucd_record!(
+    /// A code point or code point range's line breaking class, as found in
+    /// `LineBreak.txt`.
+    LineBreak,
+    class,
+    "LineBreak.txt"
+);
+
+simple_ucd_record!(
+    /// A code point or code point range's membership in a derived core
+    /// property, as found in `DerivedCoreProperties.txt`.
+    CoreProperty,
+    property,
+    "DerivedCoreProperties.txt"
+);
+
+simple_ucd_record!(
+    /// A code point or code point range's membership in an emoji property, as
+    /// found in `emoji-data.txt`.
+    EmojiProperty,
+    property,
+    "emoji-data.txt"
+);
+
+simple_ucd_record!(
+    /// A code point or code point range's grapheme cluster break class, as
+    /// found in `GraphemeBreakProperty.txt`.
+    GraphemeClusterBreak,
+    class,
+    "GraphemeBreakProperty.txt"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_single_codepoint_record() {
+        let age: Age = "0041 ; 1.1".parse().unwrap();
+        assert_eq!(age.codepoints, CodepointRange { start: 0x41, end: 0x41 });
+        assert_eq!(age.version, "1.1");
+    }
+
+    #[test]
+    fn parses_a_codepoint_range_record() {
+        let line_break: LineBreak = "0041..005A ; AL".parse().unwrap();
+        assert_eq!(
+            line_break.codepoints,
+            CodepointRange {
+                start: 0x41,
+                end: 0x5A
+            }
+        );
+        assert_eq!(line_break.class, "AL");
+    }
+
+    #[test]
+    fn rejects_a_line_missing_its_value_field() {
+        let err = match "0041".parse::<Age>() {
+            Ok(_) => panic!("expected a parse error"),
+            Err(e) => e,
+        };
+        assert!(matches!(err, FieldError::MissingField));
+    }
+
+    #[test]
+    fn rejects_a_line_with_an_invalid_codepoint() {
+        let err = match "XYZ ; 1.1".parse::<Age>() {
+            Ok(_) => panic!("expected a parse error"),
+            Err(e) => e,
+        };
+        assert!(matches!(err, FieldError::Codepoint(_)));
+    }
+
+    #[test]
+    fn file_name_matches_the_conventional_ucd_file() {
+        assert_eq!(Age::file_name(), "DerivedAge.txt");
+        assert_eq!(EmojiProperty::file_name(), "emoji-data.txt");
+    }
+}